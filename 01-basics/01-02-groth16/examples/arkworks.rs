@@ -1,18 +1,30 @@
 use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G2Affine};
-use ark_ff::PrimeField;
+use ark_ff::{BigInteger, PrimeField};
 use ark_groth16::{Groth16, PreparedVerifyingKey, Proof, VerifyingKey};
 use num_bigint::BigUint;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fs;
 
-#[derive(Debug, Deserialize)]
+fn default_protocol() -> String {
+    "groth16".to_string()
+}
+
+fn default_curve() -> String {
+    "bn128".to_string()
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 struct ProofJson {
     pi_a: [String; 3],
     pi_b: [[String; 2]; 3],
     pi_c: [String; 3],
+    #[serde(default = "default_protocol")]
+    protocol: String,
+    #[serde(default = "default_curve")]
+    curve: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 struct VKeyJson {
     #[serde(rename = "nPublic")]
     n_public: usize,
@@ -22,9 +34,13 @@ struct VKeyJson {
     vk_delta_2: [[String; 2]; 3],
     #[serde(rename = "IC")]
     ic: Vec<[String; 3]>,
+    #[serde(default = "default_protocol")]
+    protocol: String,
+    #[serde(default = "default_curve")]
+    curve: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 struct PublicSignals(Vec<String>);
 
 // Conversion helpers
@@ -40,6 +56,14 @@ fn string_to_fr(s: &str) -> Fr {
     Fr::from_be_bytes_mod_order(&bytes)
 }
 
+fn fq_to_string(fq: &Fq) -> String {
+    BigUint::from_bytes_be(&fq.into_bigint().to_bytes_be()).to_str_radix(10)
+}
+
+fn fr_to_string(fr: &Fr) -> String {
+    BigUint::from_bytes_be(&fr.into_bigint().to_bytes_be()).to_str_radix(10)
+}
+
 fn parse_g1(coords: &[String; 3]) -> G1Affine {
     let x = string_to_fq(&coords[0]);
     let y = string_to_fq(&coords[1]);
@@ -60,6 +84,51 @@ fn parse_g2(coords: &[[String; 2]; 3]) -> G2Affine {
     G2Affine::new(x, y)
 }
 
+// Render a G1 point in snarkjs format [x, y, "1"]
+fn g1_to_json(p: &G1Affine) -> [String; 3] {
+    [fq_to_string(&p.x), fq_to_string(&p.y), "1".to_string()]
+}
+
+// Render a G2 point in the same [[x_c0, x_c1], [y_c0, y_c1], ["1", "0"]]
+// layout parse_g2 above reads, so json_to_proof(proof_to_json(p)) round-trips.
+fn g2_to_json(p: &G2Affine) -> [[String; 2]; 3] {
+    [
+        [fq_to_string(&p.x.c0), fq_to_string(&p.x.c1)],
+        [fq_to_string(&p.y.c0), fq_to_string(&p.y.c1)],
+        ["1".to_string(), "0".to_string()],
+    ]
+}
+
+// Convert an ark-groth16 Proof back to the snarkjs/circom JSON shape
+fn proof_to_json(proof: &Proof<Bn254>) -> ProofJson {
+    ProofJson {
+        pi_a: g1_to_json(&proof.a),
+        pi_b: g2_to_json(&proof.b),
+        pi_c: g1_to_json(&proof.c),
+        protocol: default_protocol(),
+        curve: default_curve(),
+    }
+}
+
+// Convert an ark-groth16 VerifyingKey back to the snarkjs/circom JSON shape
+fn vkey_to_json(vk: &VerifyingKey<Bn254>) -> VKeyJson {
+    VKeyJson {
+        n_public: vk.gamma_abc_g1.len() - 1,
+        vk_alpha_1: g1_to_json(&vk.alpha_g1),
+        vk_beta_2: g2_to_json(&vk.beta_g2),
+        vk_gamma_2: g2_to_json(&vk.gamma_g2),
+        vk_delta_2: g2_to_json(&vk.delta_g2),
+        ic: vk.gamma_abc_g1.iter().map(g1_to_json).collect(),
+        protocol: default_protocol(),
+        curve: default_curve(),
+    }
+}
+
+// Convert public inputs back to the snarkjs decimal-string array format
+fn public_to_json(public_inputs: &[Fr]) -> PublicSignals {
+    PublicSignals(public_inputs.iter().map(fr_to_string).collect())
+}
+
 // Convert snarkjs JSON to ark-groth16 Proof
 fn json_to_proof(proof_json: &ProofJson) -> Proof<Bn254> {
     let a = parse_g1(&proof_json.pi_a);
@@ -134,7 +203,25 @@ fn main() {
     println!("✓ Conversion complete");
     println!("  Proof points: A, B, C");
     println!("  VKey points: α, β, γ, δ, {} IC points\n", vk.gamma_abc_g1.len());
-    
+
+    // Roundtrip back to the snarkjs JSON shape and check we recover the
+    // exact same arkworks values, i.e. json_to_proof(proof_to_json(p)) == p.
+    println!("🔁 Roundtripping through snarkjs JSON...");
+    let roundtrip_proof = json_to_proof(&proof_to_json(&proof));
+    let roundtrip_vk = json_to_vkey(&vkey_to_json(&vk));
+    let roundtrip_public: Vec<Fr> = public_to_json(&public_inputs)
+        .0
+        .iter()
+        .map(|s| string_to_fr(s))
+        .collect();
+    assert_eq!(roundtrip_proof.a, proof.a);
+    assert_eq!(roundtrip_proof.b, proof.b);
+    assert_eq!(roundtrip_proof.c, proof.c);
+    assert_eq!(roundtrip_vk.alpha_g1, vk.alpha_g1);
+    assert_eq!(roundtrip_vk.gamma_abc_g1, vk.gamma_abc_g1);
+    assert_eq!(roundtrip_public, public_inputs);
+    println!("✓ Roundtrip matches byte-for-byte\n");
+
     // Verify using ark-groth16!
     println!("🔍 Verifying proof with ark-groth16...");
     println!("{}", "═".repeat(54));
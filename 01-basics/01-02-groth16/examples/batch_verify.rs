@@ -0,0 +1,242 @@
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::pairing::Pairing;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{PrimeField, Zero};
+use ark_groth16::{PreparedVerifyingKey, Proof, VerifyingKey};
+use num_bigint::BigUint;
+use rand::{thread_rng, Rng};
+use serde::Deserialize;
+use std::fs;
+
+#[derive(Debug, Deserialize)]
+struct ProofJson {
+    pi_a: [String; 3],
+    pi_b: [[String; 2]; 3],
+    pi_c: [String; 3],
+}
+
+#[derive(Debug, Deserialize)]
+struct VKeyJson {
+    #[serde(rename = "nPublic")]
+    n_public: usize,
+    vk_alpha_1: [String; 3],
+    vk_beta_2: [[String; 2]; 3],
+    vk_gamma_2: [[String; 2]; 3],
+    vk_delta_2: [[String; 2]; 3],
+    #[serde(rename = "IC")]
+    ic: Vec<[String; 3]>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PublicSignals(Vec<String>);
+
+fn string_to_fq(s: &str) -> Fq {
+    let bigint = BigUint::parse_bytes(s.as_bytes(), 10).expect("Invalid number");
+    let bytes = bigint.to_bytes_be();
+    Fq::from_be_bytes_mod_order(&bytes)
+}
+
+fn string_to_fr(s: &str) -> Fr {
+    let bigint = BigUint::parse_bytes(s.as_bytes(), 10).expect("Invalid number");
+    let bytes = bigint.to_bytes_be();
+    Fr::from_be_bytes_mod_order(&bytes)
+}
+
+fn parse_g1(coords: &[String; 3]) -> G1Affine {
+    let x = string_to_fq(&coords[0]);
+    let y = string_to_fq(&coords[1]);
+    G1Affine::new(x, y)
+}
+
+fn parse_g2(coords: &[[String; 2]; 3]) -> G2Affine {
+    // snarkjs format: [[x_c1, x_c0], [y_c1, y_c0], [z_c1, z_c0]]
+    let x_c0 = string_to_fq(&coords[0][0]);
+    let x_c1 = string_to_fq(&coords[0][1]);
+    let x = Fq2::new(x_c0, x_c1);
+
+    let y_c0 = string_to_fq(&coords[1][0]);
+    let y_c1 = string_to_fq(&coords[1][1]);
+    let y = Fq2::new(y_c0, y_c1);
+
+    G2Affine::new(x, y)
+}
+
+fn json_to_proof(proof_json: &ProofJson) -> Proof<Bn254> {
+    let a = parse_g1(&proof_json.pi_a);
+    let b = parse_g2(&proof_json.pi_b);
+    let c = parse_g1(&proof_json.pi_c);
+    Proof { a, b, c }
+}
+
+fn json_to_vkey(vkey_json: &VKeyJson) -> VerifyingKey<Bn254> {
+    let alpha_g1 = parse_g1(&vkey_json.vk_alpha_1);
+    let beta_g2 = parse_g2(&vkey_json.vk_beta_2);
+    let gamma_g2 = parse_g2(&vkey_json.vk_gamma_2);
+    let delta_g2 = parse_g2(&vkey_json.vk_delta_2);
+    let gamma_abc_g1: Vec<G1Affine> = vkey_json.ic.iter().map(parse_g1).collect();
+
+    VerifyingKey {
+        alpha_g1,
+        beta_g2,
+        gamma_g2,
+        delta_g2,
+        gamma_abc_g1,
+    }
+}
+
+// Computes the IC/gamma_abc_g1 linear combination `vk_x` for one proof's
+// public inputs, exactly like the single-proof `verify_groth16` does.
+fn vk_x(vk: &VerifyingKey<Bn254>, public_inputs: &[Fr]) -> G1Affine {
+    let mut vk_x = vk.gamma_abc_g1[0].into_group();
+    for (i, input) in public_inputs.iter().enumerate() {
+        vk_x += vk.gamma_abc_g1[i + 1].mul_bigint(input.into_bigint());
+    }
+    vk_x.into_affine()
+}
+
+// Samples a 128-bit random scalar, which is sufficient soundness for the
+// random linear combination below.
+fn random_scalar() -> Fr {
+    Fr::from(thread_rng().gen::<u128>())
+}
+
+/// Verifies many Groth16 proofs against a shared `VerifyingKey` in
+/// `N + 3` pairings instead of `4N`, by combining the `N` `e(A_i, B_i)`
+/// terms into one multi-Miller-loop and collapsing the shared
+/// `alpha/beta`, `gamma`, `delta` terms into one pairing each. Since the
+/// verification equation is linear in each proof, a random linear
+/// combination of `N` equations holds with overwhelming probability iff
+/// every individual equation holds.
+struct BatchVerifier {
+    pvk: PreparedVerifyingKey<Bn254>,
+}
+
+impl BatchVerifier {
+    fn new(vk: VerifyingKey<Bn254>) -> Self {
+        Self {
+            pvk: PreparedVerifyingKey::from(vk),
+        }
+    }
+
+    fn batch_verify(&self, proofs: &[Proof<Bn254>], public_inputs: &[Vec<Fr>]) -> bool {
+        assert_eq!(proofs.len(), public_inputs.len());
+        let vk = &self.pvk.vk;
+
+        let r: Vec<Fr> = proofs.iter().map(|_| random_scalar()).collect();
+
+        // sum_i r_i * A_i, paired individually against each B_i.
+        let scaled_a: Vec<G1Affine> = proofs
+            .iter()
+            .zip(&r)
+            .map(|(proof, ri)| proof.a.mul_bigint(ri.into_bigint()).into_affine())
+            .collect();
+        let bs: Vec<G2Affine> = proofs.iter().map(|proof| proof.b).collect();
+
+        // sum_i r_i, scaling the shared alpha term once. Negated like vk_x
+        // and C below, matching verify_groth16's e(-alpha, beta) term.
+        let sum_r: Fr = r.iter().sum();
+        let scaled_alpha = -vk.alpha_g1.mul_bigint(sum_r.into_bigint());
+
+        // sum_i r_i * vk_x_i and sum_i r_i * C_i, negated for the pairing check.
+        let mut sum_vk_x = ark_bn254::G1Projective::zero();
+        let mut sum_c = ark_bn254::G1Projective::zero();
+        for ((proof, inputs), ri) in proofs.iter().zip(public_inputs).zip(&r) {
+            sum_vk_x += vk_x(vk, inputs).mul_bigint(ri.into_bigint());
+            sum_c += proof.c.mul_bigint(ri.into_bigint());
+        }
+
+        let mut g1_terms = scaled_a;
+        let mut g2_terms = bs;
+        g1_terms.push(scaled_alpha.into_affine());
+        g2_terms.push(vk.beta_g2);
+        g1_terms.push((-sum_vk_x).into_affine());
+        g2_terms.push(vk.gamma_g2);
+        g1_terms.push((-sum_c).into_affine());
+        g2_terms.push(vk.delta_g2);
+
+        // One multi-Miller-loop over N+3 pairs, one final exponentiation.
+        let result = Bn254::multi_pairing(g1_terms, g2_terms);
+        result.is_zero()
+    }
+}
+
+fn main() {
+    println!("Batch Groth16 Verifier\n");
+
+    let vk_json = fs::read_to_string("../01-01-sudoku/verification_key.json")
+        .expect("Failed to read verification_key.json");
+    let proof_json =
+        fs::read_to_string("../01-01-sudoku/proof.json").expect("Failed to read proof.json");
+    let public_json =
+        fs::read_to_string("../01-01-sudoku/public.json").expect("Failed to read public.json");
+
+    let vkey_data: VKeyJson = serde_json::from_str(&vk_json).expect("Failed to parse verification key");
+    let proof_data: ProofJson = serde_json::from_str(&proof_json).expect("Failed to parse proof");
+    let public_signals: PublicSignals =
+        serde_json::from_str(&public_json).expect("Failed to parse public signals");
+
+    let vk = json_to_vkey(&vkey_data);
+    let proof = json_to_proof(&proof_data);
+    let public_inputs: Vec<Fr> = public_signals.0.iter().map(|s| string_to_fr(s)).collect();
+
+    let batch_verifier = BatchVerifier::new(vk);
+
+    // A batch of N copies of the same valid proof must verify.
+    let n = 5;
+    let proofs = vec![proof.clone(); n];
+    let inputs = vec![public_inputs.clone(); n];
+    println!(
+        "Batch of {} valid proofs: {}",
+        n,
+        batch_verifier.batch_verify(&proofs, &inputs)
+    );
+
+    // Mixing in one proof verified against tampered public inputs must fail
+    // the whole batch.
+    let mut tampered_inputs = inputs.clone();
+    tampered_inputs[2][0] += Fr::from(1u64);
+    println!(
+        "Batch with one invalid proof: {}",
+        batch_verifier.batch_verify(&proofs, &tampered_inputs)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load_batch_verifier() -> (BatchVerifier, Proof<Bn254>, Vec<Fr>) {
+        let vk_json = fs::read_to_string("../01-01-sudoku/verification_key.json")
+            .expect("Failed to read verification_key.json");
+        let proof_json =
+            fs::read_to_string("../01-01-sudoku/proof.json").expect("Failed to read proof.json");
+        let public_json =
+            fs::read_to_string("../01-01-sudoku/public.json").expect("Failed to read public.json");
+
+        let vkey_data: VKeyJson =
+            serde_json::from_str(&vk_json).expect("Failed to parse verification key");
+        let proof_data: ProofJson = serde_json::from_str(&proof_json).expect("Failed to parse proof");
+        let public_signals: PublicSignals =
+            serde_json::from_str(&public_json).expect("Failed to parse public signals");
+
+        let vk = json_to_vkey(&vkey_data);
+        let proof = json_to_proof(&proof_data);
+        let public_inputs: Vec<Fr> = public_signals.0.iter().map(|s| string_to_fr(s)).collect();
+
+        (BatchVerifier::new(vk), proof, public_inputs)
+    }
+
+    #[test]
+    fn test_batch_verify_accepts_all_valid_and_rejects_one_tampered() {
+        let (batch_verifier, proof, public_inputs) = load_batch_verifier();
+
+        let n = 5;
+        let proofs = vec![proof; n];
+        let inputs = vec![public_inputs; n];
+        assert!(batch_verifier.batch_verify(&proofs, &inputs));
+
+        let mut tampered_inputs = inputs;
+        tampered_inputs[2][0] += Fr::from(1u64);
+        assert!(!batch_verifier.batch_verify(&proofs, &tampered_inputs));
+    }
+}
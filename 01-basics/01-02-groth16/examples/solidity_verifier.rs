@@ -0,0 +1,311 @@
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_ff::PrimeField;
+use ark_groth16::{Proof, VerifyingKey};
+use num_bigint::BigUint;
+use serde::Deserialize;
+use std::fs;
+
+#[derive(Debug, Deserialize)]
+struct ProofJson {
+    pi_a: [String; 3],
+    pi_b: [[String; 2]; 3],
+    pi_c: [String; 3],
+}
+
+#[derive(Debug, Deserialize)]
+struct VKeyJson {
+    #[serde(rename = "nPublic")]
+    n_public: usize,
+    vk_alpha_1: [String; 3],
+    vk_beta_2: [[String; 2]; 3],
+    vk_gamma_2: [[String; 2]; 3],
+    vk_delta_2: [[String; 2]; 3],
+    #[serde(rename = "IC")]
+    ic: Vec<[String; 3]>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PublicSignals(Vec<String>);
+
+fn string_to_fq(s: &str) -> Fq {
+    let bigint = BigUint::parse_bytes(s.as_bytes(), 10).expect("Invalid number");
+    let bytes = bigint.to_bytes_be();
+    Fq::from_be_bytes_mod_order(&bytes)
+}
+
+fn string_to_fr(s: &str) -> Fr {
+    let bigint = BigUint::parse_bytes(s.as_bytes(), 10).expect("Invalid number");
+    let bytes = bigint.to_bytes_be();
+    Fr::from_be_bytes_mod_order(&bytes)
+}
+
+fn parse_g1(coords: &[String; 3]) -> G1Affine {
+    let x = string_to_fq(&coords[0]);
+    let y = string_to_fq(&coords[1]);
+    G1Affine::new(x, y)
+}
+
+fn parse_g2(coords: &[[String; 2]; 3]) -> G2Affine {
+    // snarkjs format: [[x_c1, x_c0], [y_c1, y_c0], [z_c1, z_c0]]
+    let x_c0 = string_to_fq(&coords[0][0]);
+    let x_c1 = string_to_fq(&coords[0][1]);
+    let x = Fq2::new(x_c0, x_c1);
+
+    let y_c0 = string_to_fq(&coords[1][0]);
+    let y_c1 = string_to_fq(&coords[1][1]);
+    let y = Fq2::new(y_c0, y_c1);
+
+    G2Affine::new(x, y)
+}
+
+fn json_to_vkey(vkey_json: &VKeyJson) -> VerifyingKey<Bn254> {
+    let alpha_g1 = parse_g1(&vkey_json.vk_alpha_1);
+    let beta_g2 = parse_g2(&vkey_json.vk_beta_2);
+    let gamma_g2 = parse_g2(&vkey_json.vk_gamma_2);
+    let delta_g2 = parse_g2(&vkey_json.vk_delta_2);
+    let gamma_abc_g1: Vec<G1Affine> = vkey_json.ic.iter().map(parse_g1).collect();
+
+    VerifyingKey {
+        alpha_g1,
+        beta_g2,
+        gamma_g2,
+        delta_g2,
+        gamma_abc_g1,
+    }
+}
+
+fn json_to_proof(proof_json: &ProofJson) -> Proof<Bn254> {
+    let a = parse_g1(&proof_json.pi_a);
+    let b = parse_g2(&proof_json.pi_b);
+    let c = parse_g1(&proof_json.pi_c);
+    Proof { a, b, c }
+}
+
+// Decimal representation of an Fq limb, as Solidity `uint256` literals expect.
+fn fq_to_decimal(fq: &Fq) -> String {
+    BigUint::from_bytes_be(&fq.into_bigint().to_bytes_be()).to_str_radix(10)
+}
+
+fn fr_to_decimal(fr: &Fr) -> String {
+    BigUint::from_bytes_be(&fr.into_bigint().to_bytes_be()).to_str_radix(10)
+}
+
+fn g1_to_decimal(p: &G1Affine) -> (String, String) {
+    (fq_to_decimal(&p.x), fq_to_decimal(&p.y))
+}
+
+// Solidity expects the BN254 G2 coordinates swapped: [x_c1, x_c0], [y_c1, y_c0].
+fn g2_to_decimal(p: &G2Affine) -> ((String, String), (String, String)) {
+    (
+        (fq_to_decimal(&p.x.c1), fq_to_decimal(&p.x.c0)),
+        (fq_to_decimal(&p.y.c1), fq_to_decimal(&p.y.c0)),
+    )
+}
+
+/// Renders a self-contained Solidity Groth16 verifier from a parsed
+/// `VerifyingKey<Bn254>`, matching the pairing check
+/// `e(A,B) == e(alpha,beta)·e(vk_x,gamma)·e(C,delta)` via the EVM
+/// precompiles at 0x06 (ecAdd), 0x07 (ecMul) and 0x08 (ecPairing).
+struct SolidityGenerator {
+    vk: VerifyingKey<Bn254>,
+    n_public: usize,
+}
+
+impl SolidityGenerator {
+    fn new(vk: VerifyingKey<Bn254>, n_public: usize) -> Self {
+        assert_eq!(
+            vk.gamma_abc_g1.len(),
+            n_public + 1,
+            "IC length must be n_public + 1"
+        );
+        Self { vk, n_public }
+    }
+
+    /// Renders the `Verifier.sol` contract source.
+    fn render(&self) -> String {
+        let (alpha_x, alpha_y) = g1_to_decimal(&self.vk.alpha_g1);
+        let (beta_x, beta_y) = g2_to_decimal(&self.vk.beta_g2);
+        let (gamma_x, gamma_y) = g2_to_decimal(&self.vk.gamma_g2);
+        let (delta_x, delta_y) = g2_to_decimal(&self.vk.delta_g2);
+
+        let ic_entries = self
+            .vk
+            .gamma_abc_g1
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                let (x, y) = g1_to_decimal(p);
+                format!("        ic[{i}] = [{x}, {y}];")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            r#"// SPDX-License-Identifier: MIT
+// Generated by the Groth16 SolidityGenerator. Do not edit by hand.
+pragma solidity ^0.8.19;
+
+contract Verifier {{
+    uint256 constant ALPHA_X = {alpha_x};
+    uint256 constant ALPHA_Y = {alpha_y};
+    uint256 constant BETA_X1 = {beta_x_1};
+    uint256 constant BETA_X0 = {beta_x_0};
+    uint256 constant BETA_Y1 = {beta_y_1};
+    uint256 constant BETA_Y0 = {beta_y_0};
+    uint256 constant GAMMA_X1 = {gamma_x_1};
+    uint256 constant GAMMA_X0 = {gamma_x_0};
+    uint256 constant GAMMA_Y1 = {gamma_y_1};
+    uint256 constant GAMMA_Y0 = {gamma_y_0};
+    uint256 constant DELTA_X1 = {delta_x_1};
+    uint256 constant DELTA_X0 = {delta_x_0};
+    uint256 constant DELTA_Y1 = {delta_y_1};
+    uint256 constant DELTA_Y0 = {delta_y_0};
+
+    uint256 constant N_PUBLIC = {n_public};
+
+    function ic() internal pure returns (uint256[2][{ic_len}] memory ic) {{
+{ic_entries}
+    }}
+
+    function verifyProof(
+        uint256[2] memory a,
+        uint256[2][2] memory b,
+        uint256[2] memory c,
+        uint256[] memory input
+    ) public view returns (bool) {{
+        require(input.length == N_PUBLIC, "invalid input length");
+
+        uint256[2][{ic_len}] memory points = ic();
+        uint256[2] memory vkX = points[0];
+        for (uint256 i = 0; i < input.length; i++) {{
+            vkX = pointAdd(vkX, scalarMul(points[i + 1], input[i]));
+        }}
+
+        return pairingCheck(a, b, vkX, c);
+    }}
+
+    function scalarMul(uint256[2] memory p, uint256 s) internal view returns (uint256[2] memory r) {{
+        uint256[3] memory input_ = [p[0], p[1], s];
+        bool ok;
+        assembly {{
+            ok := staticcall(gas(), 0x07, input_, 0x60, r, 0x40)
+        }}
+        require(ok, "ecmul failed");
+    }}
+
+    function pointAdd(uint256[2] memory p1, uint256[2] memory p2) internal view returns (uint256[2] memory r) {{
+        uint256[4] memory input_ = [p1[0], p1[1], p2[0], p2[1]];
+        bool ok;
+        assembly {{
+            ok := staticcall(gas(), 0x06, input_, 0x80, r, 0x40)
+        }}
+        require(ok, "ecadd failed");
+    }}
+
+    function pairingCheck(
+        uint256[2] memory a,
+        uint256[2][2] memory b,
+        uint256[2] memory vkX,
+        uint256[2] memory c
+    ) internal view returns (bool) {{
+        // e(A,B) * e(-alpha,beta) * e(-vk_x,gamma) * e(-C,delta) == 1
+        uint256 negAY = ALPHA_Y == 0 ? 0 : FIELD_MODULUS() - ALPHA_Y;
+        uint256 negVkXY = vkX[1] == 0 ? 0 : FIELD_MODULUS() - vkX[1];
+        uint256 negCY = c[1] == 0 ? 0 : FIELD_MODULUS() - c[1];
+
+        uint256[24] memory input_ = [
+            a[0], a[1], b[0][0], b[0][1], b[1][0], b[1][1],
+            ALPHA_X, negAY, BETA_X1, BETA_X0, BETA_Y1, BETA_Y0,
+            vkX[0], negVkXY, GAMMA_X1, GAMMA_X0, GAMMA_Y1, GAMMA_Y0,
+            c[0], negCY, DELTA_X1, DELTA_X0, DELTA_Y1, DELTA_Y0
+        ];
+        uint256[1] memory out;
+        bool ok;
+        assembly {{
+            ok := staticcall(gas(), 0x08, input_, 0x300, out, 0x20)
+        }}
+        return ok && out[0] == 1;
+    }}
+
+    function FIELD_MODULUS() internal pure returns (uint256) {{
+        return 21888242871839275222246405745257275088696311157297823662689037894645226208583;
+    }}
+}}
+"#,
+            alpha_x = alpha_x,
+            alpha_y = alpha_y,
+            beta_x_1 = beta_x.0,
+            beta_x_0 = beta_x.1,
+            beta_y_1 = beta_y.0,
+            beta_y_0 = beta_y.1,
+            gamma_x_1 = gamma_x.0,
+            gamma_x_0 = gamma_x.1,
+            gamma_y_1 = gamma_y.0,
+            gamma_y_0 = gamma_y.1,
+            delta_x_1 = delta_x.0,
+            delta_x_0 = delta_x.1,
+            delta_y_1 = delta_y.0,
+            delta_y_0 = delta_y.1,
+            n_public = self.n_public,
+            ic_len = self.vk.gamma_abc_g1.len(),
+            ic_entries = ic_entries,
+        )
+    }
+
+    /// Formats a proof and its public inputs into the ABI layout that
+    /// `verifyProof(uint[2], uint[2][2], uint[2], uint[])` expects.
+    fn encode_calldata(proof: &Proof<Bn254>, public_inputs: &[Fr]) -> String {
+        let (a_x, a_y) = g1_to_decimal(&proof.a);
+        let (b_x, b_y) = g2_to_decimal(&proof.b);
+        let (c_x, c_y) = g1_to_decimal(&proof.c);
+        let inputs = public_inputs
+            .iter()
+            .map(fr_to_decimal)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!(
+            "verifyProof(\n  [{a_x}, {a_y}],\n  [[{b_x_1}, {b_x_0}], [{b_y_1}, {b_y_0}]],\n  [{c_x}, {c_y}],\n  [{inputs}]\n)",
+            a_x = a_x,
+            a_y = a_y,
+            b_x_1 = b_x.0,
+            b_x_0 = b_x.1,
+            b_y_1 = b_y.0,
+            b_y_0 = b_y.1,
+            c_x = c_x,
+            c_y = c_y,
+            inputs = inputs,
+        )
+    }
+}
+
+fn main() {
+    println!("Solidity Verifier Generator\n");
+
+    let vk_json =
+        fs::read_to_string("../01-01-sudoku/verification_key.json").expect("Failed to read verification_key.json");
+    let proof_json =
+        fs::read_to_string("../01-01-sudoku/proof.json").expect("Failed to read proof.json");
+    let public_json =
+        fs::read_to_string("../01-01-sudoku/public.json").expect("Failed to read public.json");
+
+    let vkey_data: VKeyJson = serde_json::from_str(&vk_json).expect("Failed to parse verification key");
+    let proof_data: ProofJson = serde_json::from_str(&proof_json).expect("Failed to parse proof");
+    let public_signals: PublicSignals =
+        serde_json::from_str(&public_json).expect("Failed to parse public signals");
+
+    let vk = json_to_vkey(&vkey_data);
+    let proof = json_to_proof(&proof_data);
+    let public_inputs: Vec<Fr> = public_signals.0.iter().map(|s| string_to_fr(s)).collect();
+
+    let generator = SolidityGenerator::new(vk, vkey_data.n_public);
+    let source = generator.render();
+
+    let out_path = "Verifier.sol";
+    fs::write(out_path, &source).expect("Failed to write Verifier.sol");
+    println!("Wrote {} ({} bytes)", out_path, source.len());
+
+    println!("\nCalldata for the loaded proof:");
+    println!("{}", SolidityGenerator::encode_calldata(&proof, &public_inputs));
+}
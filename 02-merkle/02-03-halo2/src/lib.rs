@@ -1,16 +1,106 @@
 use ff::Field;
 use halo2_gadgets::poseidon::{
-    primitives::{ConstantLength, P128Pow5T3 as OrchardNullifier},
+    primitives::{self as poseidon, ConstantLength, P128Pow5T3 as OrchardNullifier},
     Hash, Pow5Chip, Pow5Config,
 };
 use halo2_proofs::{
     circuit::{Layouter, SimpleFloorPlanner, Value},
     plonk::{
-        Advice, Circuit, Column, ConstraintSystem, Error, Expression, Instance, Selector,
+        create_proof, keygen_pk, keygen_vk, verify_proof, Advice, Circuit, Column,
+        ConstraintSystem, Error, Expression, Instance, ProvingKey, Selector, SingleVerifier,
+        VerifyingKey,
     },
-    poly::Rotation,
+    poly::{commitment::Params, Rotation},
+    transcript::{Blake2bRead, Blake2bWrite, Challenge255},
 };
-use pasta_curves::pallas;
+use pasta_curves::{pallas, vesta};
+use rand_core::OsRng;
+
+fn poseidon_hash1(x: pallas::Base) -> pallas::Base {
+    poseidon::Hash::<_, OrchardNullifier, ConstantLength<1>, 3, 2>::init().hash([x])
+}
+
+fn poseidon_hash2(left: pallas::Base, right: pallas::Base) -> pallas::Base {
+    poseidon::Hash::<_, OrchardNullifier, ConstantLength<2>, 3, 2>::init().hash([left, right])
+}
+
+/// A native (off-circuit) Poseidon-hashed binary Merkle tree over a leaf
+/// vector, used to build witnesses for [`MerkleCircuit`] without having to
+/// hand-compute siblings and path indices.
+#[allow(dead_code)]
+struct MerkleTree<const DEPTH: usize> {
+    // layers[0] is the (zero-padded) leaves, layers[DEPTH] is the single root.
+    layers: Vec<Vec<pallas::Base>>,
+}
+
+#[allow(dead_code)]
+impl<const DEPTH: usize> MerkleTree<DEPTH> {
+    fn new(mut leaves: Vec<pallas::Base>) -> Self {
+        let capacity = 1usize << DEPTH;
+        assert!(
+            leaves.len() <= capacity,
+            "tree of depth {} can hold at most {} leaves",
+            DEPTH,
+            capacity
+        );
+        leaves.resize(capacity, pallas::Base::ZERO);
+
+        let mut layers = vec![leaves];
+        for _ in 0..DEPTH {
+            let prev = layers.last().unwrap();
+            let next = prev
+                .chunks(2)
+                .map(|pair| poseidon_hash2(pair[0], pair[1]))
+                .collect();
+            layers.push(next);
+        }
+
+        Self { layers }
+    }
+
+    fn root(&self) -> pallas::Base {
+        self.layers[DEPTH][0]
+    }
+
+    fn get_witness(&self, index: usize) -> ([pallas::Base; DEPTH], [bool; DEPTH]) {
+        let mut siblings = [pallas::Base::ZERO; DEPTH];
+        let mut path_indices = [false; DEPTH];
+
+        let mut idx = index;
+        for (level, layer) in self.layers[..DEPTH].iter().enumerate() {
+            let is_right = idx % 2 == 1;
+            let sibling_idx = if is_right { idx - 1 } else { idx + 1 };
+            siblings[level] = layer[sibling_idx];
+            path_indices[level] = is_right;
+            idx /= 2;
+        }
+
+        (siblings, path_indices)
+    }
+
+    fn check_inclusion(
+        &self,
+        leaf: pallas::Base,
+        witness: &([pallas::Base; DEPTH], [bool; DEPTH]),
+        index: usize,
+    ) -> bool {
+        if self.layers[0].get(index) != Some(&leaf) {
+            return false;
+        }
+
+        let (siblings, path_indices) = witness;
+        let mut current = leaf;
+        for level in 0..DEPTH {
+            current = if path_indices[level] {
+                poseidon_hash2(siblings[level], current)
+            } else {
+                poseidon_hash2(current, siblings[level])
+            };
+        }
+
+        current == self.root()
+    }
+}
 
 #[allow(dead_code)]
 #[derive(Clone, Debug)]
@@ -60,13 +150,31 @@ impl MerkleConfig {
 
 #[allow(dead_code)]
 #[derive(Clone, Debug, Default)]
-struct MerkleCircuit {
+struct MerkleCircuit<const DEPTH: usize> {
     secret: Value<pallas::Base>,
-    siblings: Value<[pallas::Base; 3]>,
-    path_indices: Value<[bool; 3]>,
+    siblings: Value<[pallas::Base; DEPTH]>,
+    path_indices: Value<[bool; DEPTH]>,
+}
+
+#[allow(dead_code)]
+impl<const DEPTH: usize> MerkleCircuit<DEPTH> {
+    /// `k` (the circuit has `2^k` rows) used for this depth. Each tree
+    /// level synthesizes one swap gate plus one 2-to-1 Poseidon hash, on top
+    /// of the fixed cost of the leaf/nullifier hashes. `k` starts at 11 for
+    /// depths up to 8 and grows by one each time the depth doubles past
+    /// that; this is a generous bound, not the smallest `k` that fits.
+    fn k() -> u32 {
+        let mut k = 11u32;
+        let mut capacity = 8usize;
+        while DEPTH > capacity {
+            k += 1;
+            capacity *= 2;
+        }
+        k
+    }
 }
 
-impl Circuit<pallas::Base> for MerkleCircuit {
+impl<const DEPTH: usize> Circuit<pallas::Base> for MerkleCircuit<DEPTH> {
     type Config = MerkleConfig;
     type FloorPlanner = SimpleFloorPlanner;
 
@@ -163,7 +271,7 @@ impl Circuit<pallas::Base> for MerkleCircuit {
         // Climb tree with proper path selection
         let mut current = leaf;
 
-        for i in 0..3 {
+        for i in 0..DEPTH {
             let (left, right) = layouter.assign_region(
                 || format!("swap level {}", i),
                 |mut region| {
@@ -233,63 +341,453 @@ impl Circuit<pallas::Base> for MerkleCircuit {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use halo2_gadgets::poseidon::primitives::{self as poseidon, P128Pow5T3};
-    use halo2_proofs::{dev::MockProver, pasta::Fp};
+/// Proves a `MerkleCircuit<DEPTH>` statement and returns the serialized
+/// proof bytes. The Fiat-Shamir transcript is hashed with Blake2b, so the
+/// bytes are self-contained and can be verified independently of this
+/// process (see [`verify_merkle_proof`]).
+#[allow(dead_code)]
+fn prove_merkle<const DEPTH: usize>(
+    params: &Params<vesta::Affine>,
+    pk: &ProvingKey<vesta::Affine>,
+    circuit: MerkleCircuit<DEPTH>,
+    public_inputs: &[pallas::Base],
+) -> Vec<u8> {
+    let mut transcript = Blake2bWrite::<_, vesta::Affine, Challenge255<_>>::init(vec![]);
+    create_proof(
+        params,
+        pk,
+        &[circuit],
+        &[&[public_inputs]],
+        OsRng,
+        &mut transcript,
+    )
+    .expect("proof generation should not fail");
+    transcript.finalize()
+}
 
-    #[test]
-    fn test_merkle_left_path() {
-        let k = 11;
-        let secret = Fp::from(12345);
+/// Verifies proof bytes produced by [`prove_merkle`] against the given
+/// public inputs, reading the same Blake2b transcript back.
+#[allow(dead_code)]
+fn verify_merkle_proof(
+    params: &Params<vesta::Affine>,
+    vk: &VerifyingKey<vesta::Affine>,
+    proof_bytes: &[u8],
+    public_inputs: &[pallas::Base],
+) -> bool {
+    let strategy = SingleVerifier::new(params);
+    let mut transcript = Blake2bRead::<_, vesta::Affine, Challenge255<_>>::init(proof_bytes);
+    verify_proof(params, vk, strategy, &[&[public_inputs]], &mut transcript).is_ok()
+}
+
+/// Config for [`RlnCircuit`]: reuses the Merkle membership config and adds a
+/// selector for the Shamir line-evaluation gate.
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct RlnConfig {
+    merkle: MerkleConfig,
+    linear_selector: Selector,
+}
 
-        // Compute expected values
-        let leaf = poseidon::Hash::<_, P128Pow5T3, ConstantLength<1>, 3, 2>::init().hash([secret]);
-        let siblings = [Fp::zero(); 3];
-        let path_indices = [false; 3]; // All left
+impl RlnConfig {
+    /// Constrains `share_y = identity_secret + a1 * x`, the degree-1
+    /// polynomial evaluation that makes two signals in the same epoch
+    /// leak their `identity_secret` when Lagrange-interpolated off-circuit.
+    fn configure_linear_gate(&self, meta: &mut ConstraintSystem<pallas::Base>) {
+        meta.create_gate("rln linear evaluation", |meta| {
+            let s = meta.query_selector(self.linear_selector);
+
+            let identity_secret = meta.query_advice(self.merkle.advices[5], Rotation::cur());
+            let a1 = meta.query_advice(self.merkle.advices[6], Rotation::cur());
+            let x = meta.query_advice(self.merkle.advices[7], Rotation::cur());
+            let share_y = meta.query_advice(self.merkle.advices[8], Rotation::cur());
+
+            vec![s * (share_y - identity_secret - a1 * x)]
+        });
+    }
+}
+
+/// Rate-Limiting Nullifier circuit: proves membership of `hash(identity_secret)`
+/// in the tree (like [`MerkleCircuit`]) and additionally proves that
+/// `(x, share_y)` lies on the line `y = identity_secret + a1 * x`, where
+/// `a1 = Poseidon(identity_secret, external_nullifier)` and
+/// `external_nullifier = Poseidon(epoch, rln_id)`.
+///
+/// Two proofs sharing `external_nullifier` (i.e. the same epoch) but with
+/// different message hashes `x1 != x2` leak two points on the same line, so
+/// an off-circuit helper can Lagrange-interpolate `identity_secret` back out.
+#[allow(dead_code)]
+#[derive(Clone, Debug, Default)]
+struct RlnCircuit {
+    identity_secret: Value<pallas::Base>,
+    siblings: Value<[pallas::Base; 3]>,
+    path_indices: Value<[bool; 3]>,
+    epoch: Value<pallas::Base>,
+    rln_id: Value<pallas::Base>,
+    x: Value<pallas::Base>,
+}
+
+impl Circuit<pallas::Base> for RlnCircuit {
+    type Config = RlnConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+        let advices = [
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+        ];
+
+        for advice in advices.iter() {
+            meta.enable_equality(*advice);
+        }
+
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        let lagrange_coeffs = [
+            meta.fixed_column(),
+            meta.fixed_column(),
+            meta.fixed_column(),
+            meta.fixed_column(),
+            meta.fixed_column(),
+            meta.fixed_column(),
+            meta.fixed_column(),
+            meta.fixed_column(),
+        ];
+        meta.enable_constant(lagrange_coeffs[0]);
+
+        let poseidon_config = Pow5Chip::configure::<OrchardNullifier>(
+            meta,
+            advices[0..3].try_into().unwrap(),
+            advices[3],
+            lagrange_coeffs[0..3].try_into().unwrap(),
+            lagrange_coeffs[3..6].try_into().unwrap(),
+        );
+
+        let selector = meta.selector();
+
+        let merkle = MerkleConfig {
+            advices,
+            poseidon_config,
+            instance,
+            selector,
+        };
+        merkle.configure_swap_gate(meta);
+
+        let linear_selector = meta.selector();
+        let config = RlnConfig {
+            merkle,
+            linear_selector,
+        };
+        config.configure_linear_gate(meta);
+
+        config
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        let merkle = &config.merkle;
+
+        // Load identity_secret
+        let identity_secret = layouter.assign_region(
+            || "load identity secret",
+            |mut region| {
+                region.assign_advice(
+                    || "identity_secret",
+                    merkle.advices[0],
+                    0,
+                    || self.identity_secret,
+                )
+            },
+        )?;
+
+        // Compute leaf = hash(identity_secret) and climb the tree to the root,
+        // exactly like MerkleCircuit.
+        let leaf = {
+            let poseidon_chip = Pow5Chip::construct(merkle.poseidon_config.clone());
+            let hasher = Hash::<_, _, OrchardNullifier, ConstantLength<1>, 3, 2>::init(
+                poseidon_chip,
+                layouter.namespace(|| "leaf hasher"),
+            )?;
+            hasher.hash(
+                layouter.namespace(|| "hash leaf"),
+                [identity_secret.clone()],
+            )?
+        };
 
-        // Compute root
         let mut current = leaf;
         for i in 0..3 {
-            let (left, right) = if path_indices[i] {
-                (siblings[i], current)
-            } else {
-                (current, siblings[i])
-            };
-            current = poseidon::Hash::<_, P128Pow5T3, ConstantLength<2>, 3, 2>::init()
-                .hash([left, right]);
+            let (left, right) = layouter.assign_region(
+                || format!("swap level {}", i),
+                |mut region| {
+                    merkle.selector.enable(&mut region, 0)?;
+
+                    let current_copy =
+                        current.copy_advice(|| "current", &mut region, merkle.advices[5], 0)?;
+
+                    let sibling = self.siblings.map(|siblings| siblings[i]);
+                    region.assign_advice(|| "sibling", merkle.advices[6], 0, || sibling)?;
+
+                    let path_index = self.path_indices.map(|indices| {
+                        if indices[i] {
+                            pallas::Base::ONE
+                        } else {
+                            pallas::Base::ZERO
+                        }
+                    });
+                    region.assign_advice(|| "path_index", merkle.advices[7], 0, || path_index)?;
+
+                    let left_value = self
+                        .path_indices
+                        .zip(sibling)
+                        .zip(current_copy.value().copied())
+                        .map(|((indices, sib), cur)| if indices[i] { sib } else { cur });
+
+                    let right_value = self
+                        .path_indices
+                        .zip(sibling)
+                        .zip(current_copy.value().copied())
+                        .map(|((indices, sib), cur)| if indices[i] { cur } else { sib });
+
+                    let left_cell =
+                        region.assign_advice(|| "left", merkle.advices[8], 0, || left_value)?;
+                    let right_cell =
+                        region.assign_advice(|| "right", merkle.advices[9], 0, || right_value)?;
+
+                    Ok((left_cell, right_cell))
+                },
+            )?;
+
+            let poseidon_chip = Pow5Chip::construct(merkle.poseidon_config.clone());
+            let hasher = Hash::<_, _, OrchardNullifier, ConstantLength<2>, 3, 2>::init(
+                poseidon_chip,
+                layouter.namespace(|| format!("tree hasher {}", i)),
+            )?;
+            current = hasher.hash(
+                layouter.namespace(|| format!("hash level {}", i)),
+                [left, right],
+            )?;
         }
         let root = current;
 
-        let nullifier =
-            poseidon::Hash::<_, P128Pow5T3, ConstantLength<1>, 3, 2>::init().hash([secret]);
+        // external_nullifier = hash(epoch, rln_id)
+        let (epoch, rln_id) = layouter.assign_region(
+            || "load epoch and rln_id",
+            |mut region| {
+                let epoch = region.assign_advice(|| "epoch", merkle.advices[1], 0, || self.epoch)?;
+                let rln_id =
+                    region.assign_advice(|| "rln_id", merkle.advices[2], 0, || self.rln_id)?;
+                Ok((epoch, rln_id))
+            },
+        )?;
+        let external_nullifier = {
+            let poseidon_chip = Pow5Chip::construct(merkle.poseidon_config.clone());
+            let hasher = Hash::<_, _, OrchardNullifier, ConstantLength<2>, 3, 2>::init(
+                poseidon_chip,
+                layouter.namespace(|| "external nullifier hasher"),
+            )?;
+            hasher.hash(
+                layouter.namespace(|| "hash external nullifier"),
+                [epoch, rln_id],
+            )?
+        };
+
+        // a1 = Poseidon(identity_secret, external_nullifier)
+        let a1 = {
+            let poseidon_chip = Pow5Chip::construct(merkle.poseidon_config.clone());
+            let hasher = Hash::<_, _, OrchardNullifier, ConstantLength<2>, 3, 2>::init(
+                poseidon_chip,
+                layouter.namespace(|| "a1 hasher"),
+            )?;
+            hasher.hash(
+                layouter.namespace(|| "hash a1"),
+                [identity_secret.clone(), external_nullifier.clone()],
+            )?
+        };
+
+        // Shamir share: share_y = identity_secret + a1 * x
+        let (x, share_y) = layouter.assign_region(
+            || "linear share evaluation",
+            |mut region| {
+                config.linear_selector.enable(&mut region, 0)?;
+
+                let identity_secret_copy = identity_secret.copy_advice(
+                    || "identity_secret",
+                    &mut region,
+                    merkle.advices[5],
+                    0,
+                )?;
+                let a1_copy = a1.copy_advice(|| "a1", &mut region, merkle.advices[6], 0)?;
+                let x_cell = region.assign_advice(|| "x", merkle.advices[7], 0, || self.x)?;
+
+                let share_y_value = identity_secret_copy
+                    .value()
+                    .copied()
+                    .zip(a1_copy.value().copied())
+                    .zip(x_cell.value().copied())
+                    .map(|((secret, a1), x)| secret + a1 * x);
+                let share_y_cell = region.assign_advice(
+                    || "share_y",
+                    merkle.advices[8],
+                    0,
+                    || share_y_value,
+                )?;
+
+                Ok((x_cell, share_y_cell))
+            },
+        )?;
+
+        // nullifier = Poseidon(a1)
+        let nullifier = {
+            let poseidon_chip = Pow5Chip::construct(merkle.poseidon_config.clone());
+            let hasher = Hash::<_, _, OrchardNullifier, ConstantLength<1>, 3, 2>::init(
+                poseidon_chip,
+                layouter.namespace(|| "rln nullifier hasher"),
+            )?;
+            hasher.hash(layouter.namespace(|| "hash rln nullifier"), [a1])?
+        };
+
+        layouter.constrain_instance(root.cell(), merkle.instance, 0)?;
+        layouter.constrain_instance(x.cell(), merkle.instance, 1)?;
+        layouter.constrain_instance(share_y.cell(), merkle.instance, 2)?;
+        layouter.constrain_instance(nullifier.cell(), merkle.instance, 3)?;
+        layouter.constrain_instance(external_nullifier.cell(), merkle.instance, 4)?;
+
+        Ok(())
+    }
+}
 
-        // Create circuit
-        let circuit = MerkleCircuit {
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    // Builds an 8-leaf (DEPTH=3) tree holding `hash(secret)` at `index` among
+    // otherwise arbitrary leaves, and proves membership via the witness the
+    // tree hands back - no manual root climbing needed.
+    fn run_merkle_test(secret: Fp, index: usize, other_leaves: [Fp; 7]) {
+        let leaf = poseidon_hash1(secret);
+
+        let mut leaves = other_leaves.to_vec();
+        leaves.insert(index, leaf);
+        leaves.truncate(8);
+
+        let tree = MerkleTree::<3>::new(leaves);
+        let witness = tree.get_witness(index);
+        assert!(tree.check_inclusion(leaf, &witness, index));
+
+        let (siblings, path_indices) = witness;
+        let root = tree.root();
+        let nullifier = poseidon_hash1(secret);
+
+        let circuit = MerkleCircuit::<3> {
             secret: Value::known(secret),
             siblings: Value::known(siblings),
             path_indices: Value::known(path_indices),
         };
 
         let public_inputs = vec![root, nullifier];
-        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        let prover =
+            MockProver::run(MerkleCircuit::<3>::k(), &circuit, vec![public_inputs]).unwrap();
         assert_eq!(prover.verify(), Ok(()));
+    }
 
+    #[test]
+    fn test_merkle_left_path() {
+        // index 0b000: left at every level
+        run_merkle_test(Fp::from(12345), 0, [Fp::zero(); 7]);
         println!("✅ Left path works!");
     }
 
     #[test]
     fn test_merkle_right_path() {
-        let k = 11;
-        let secret = Fp::from(67890);
+        // index 0b111: right at every level
+        let others = [
+            Fp::from(111),
+            Fp::from(222),
+            Fp::from(333),
+            Fp::from(444),
+            Fp::from(555),
+            Fp::from(666),
+            Fp::from(777),
+        ];
+        run_merkle_test(Fp::from(67890), 7, others);
+        println!("✅ Right path works!");
+    }
 
-        // Compute expected values
-        let leaf = poseidon::Hash::<_, P128Pow5T3, ConstantLength<1>, 3, 2>::init().hash([secret]);
-        let siblings = [Fp::from(111), Fp::from(222), Fp::from(333)];
-        let path_indices = [true; 3]; // All right
+    #[test]
+    fn test_merkle_mixed_path() {
+        // index 0b010: left, right, left (bit0 = level0, ...)
+        let others = [
+            Fp::from(10),
+            Fp::from(20),
+            Fp::from(30),
+            Fp::from(40),
+            Fp::from(50),
+            Fp::from(60),
+            Fp::from(70),
+        ];
+        run_merkle_test(Fp::from(99999), 2, others);
+        println!("✅ Mixed path works!");
+    }
+
+    #[test]
+    fn test_merkle_deep_tree() {
+        // A depth-8 (256-leaf) tree is a more realistic size than the
+        // original hardcoded depth-3 circuit could express.
+        const DEPTH: usize = 8;
+        let secret = Fp::from(2025);
+        let leaf = poseidon_hash1(secret);
+        let index = 200;
+
+        let mut leaves = vec![Fp::zero(); 1 << DEPTH];
+        leaves[index] = leaf;
+
+        let tree = MerkleTree::<DEPTH>::new(leaves);
+        let witness = tree.get_witness(index);
+        assert!(tree.check_inclusion(leaf, &witness, index));
+
+        let (siblings, path_indices) = witness;
+        let root = tree.root();
+        let nullifier = poseidon_hash1(secret);
+
+        let circuit = MerkleCircuit::<DEPTH> {
+            secret: Value::known(secret),
+            siblings: Value::known(siblings),
+            path_indices: Value::known(path_indices),
+        };
+
+        let public_inputs = vec![root, nullifier];
+        let prover =
+            MockProver::run(MerkleCircuit::<DEPTH>::k(), &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+
+        println!("✅ Depth-{} tree works!", DEPTH);
+    }
+
+    fn rln_root_and_external_nullifier(
+        identity_secret: Fp,
+        siblings: [Fp; 3],
+        path_indices: [bool; 3],
+        epoch: Fp,
+        rln_id: Fp,
+    ) -> (Fp, Fp) {
+        let leaf = poseidon_hash1(identity_secret);
 
-        // Compute root
         let mut current = leaf;
         for i in 0..3 {
             let (left, right) = if path_indices[i] {
@@ -297,63 +795,120 @@ mod tests {
             } else {
                 (current, siblings[i])
             };
-            current = poseidon::Hash::<_, P128Pow5T3, ConstantLength<2>, 3, 2>::init()
-                .hash([left, right]);
+            current = poseidon_hash2(left, right);
         }
-        let root = current;
 
-        let nullifier =
-            poseidon::Hash::<_, P128Pow5T3, ConstantLength<1>, 3, 2>::init().hash([secret]);
+        let external_nullifier = poseidon_hash2(epoch, rln_id);
 
-        // Create circuit
-        let circuit = MerkleCircuit {
-            secret: Value::known(secret),
+        (current, external_nullifier)
+    }
+
+    #[test]
+    fn test_rln_circuit_satisfies() {
+        let k = 11;
+        let identity_secret = Fp::from(424242);
+        let siblings = [Fp::from(10), Fp::from(20), Fp::from(30)];
+        let path_indices = [false, true, false];
+        let epoch = Fp::from(7);
+        let rln_id = Fp::from(1);
+        let x = Fp::from(555);
+
+        let (root, external_nullifier) = rln_root_and_external_nullifier(
+            identity_secret,
+            siblings,
+            path_indices,
+            epoch,
+            rln_id,
+        );
+        let a1 = poseidon_hash2(identity_secret, external_nullifier);
+        let share_y = identity_secret + a1 * x;
+        let nullifier = poseidon_hash1(a1);
+
+        let circuit = RlnCircuit {
+            identity_secret: Value::known(identity_secret),
             siblings: Value::known(siblings),
             path_indices: Value::known(path_indices),
+            epoch: Value::known(epoch),
+            rln_id: Value::known(rln_id),
+            x: Value::known(x),
         };
 
-        let public_inputs = vec![root, nullifier];
+        let public_inputs = vec![root, x, share_y, nullifier, external_nullifier];
         let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
         assert_eq!(prover.verify(), Ok(()));
 
-        println!("✅ Right path works!");
+        println!("✅ RLN single signal is valid!");
     }
 
     #[test]
-    fn test_merkle_mixed_path() {
-        let k = 11;
-        let secret = Fp::from(99999);
+    fn test_rln_recovers_secret_from_two_shares() {
+        // Two signals from the same identity in the same epoch (same
+        // external_nullifier, hence the same line coefficient a1) but with
+        // different message hashes must leak identity_secret when combined.
+        let identity_secret = Fp::from(424242);
+        let epoch = Fp::from(7);
+        let rln_id = Fp::from(1);
 
-        let leaf = poseidon::Hash::<_, P128Pow5T3, ConstantLength<1>, 3, 2>::init().hash([secret]);
-        let siblings = [Fp::from(10), Fp::from(20), Fp::from(30)];
-        let path_indices = [false, true, false]; // left, right, left
+        let external_nullifier = poseidon_hash2(epoch, rln_id);
+        let a1 = poseidon_hash2(identity_secret, external_nullifier);
 
-        // Compute root
-        let mut current = leaf;
-        for i in 0..3 {
-            let (left, right) = if path_indices[i] {
-                (siblings[i], current)
-            } else {
-                (current, siblings[i])
-            };
-            current = poseidon::Hash::<_, P128Pow5T3, ConstantLength<2>, 3, 2>::init()
-                .hash([left, right]);
-        }
-        let root = current;
+        let x1 = Fp::from(111);
+        let x2 = Fp::from(222);
+        let y1 = identity_secret + a1 * x1;
+        let y2 = identity_secret + a1 * x2;
 
-        let nullifier =
-            poseidon::Hash::<_, P128Pow5T3, ConstantLength<1>, 3, 2>::init().hash([secret]);
+        // Lagrange-interpolate the line back to its constant term:
+        // identity_secret = (y1*x2 - y2*x1) / (x2 - x1)
+        let recovered = (y1 * x2 - y2 * x1) * (x2 - x1).invert().unwrap();
 
-        let circuit = MerkleCircuit {
+        assert_eq!(recovered, identity_secret);
+
+        println!("✅ Two leaked shares recover the identity secret!");
+    }
+
+    #[test]
+    fn test_real_proof_roundtrips_through_bytes() {
+        let k = MerkleCircuit::<3>::k();
+        let secret = Fp::from(31337);
+        let leaf = poseidon_hash1(secret);
+
+        let mut leaves = vec![Fp::zero(); 8];
+        leaves[5] = leaf;
+        let tree = MerkleTree::<3>::new(leaves);
+        let (siblings, path_indices) = tree.get_witness(5);
+        let root = tree.root();
+        let nullifier = poseidon_hash1(secret);
+        let public_inputs = vec![root, nullifier];
+
+        let params = Params::<vesta::Affine>::new(k);
+        let empty_circuit = MerkleCircuit::<3>::default();
+        let vk = keygen_vk(&params, &empty_circuit).expect("keygen_vk failed");
+        let pk = keygen_pk(&params, vk, &empty_circuit).expect("keygen_pk failed");
+
+        let circuit = MerkleCircuit::<3> {
             secret: Value::known(secret),
             siblings: Value::known(siblings),
             path_indices: Value::known(path_indices),
         };
 
-        let public_inputs = vec![root, nullifier];
-        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
-        assert_eq!(prover.verify(), Ok(()));
-
-        println!("✅ Mixed path works!");
+        let proof_bytes = prove_merkle(&params, &pk, circuit, &public_inputs);
+        assert!(verify_merkle_proof(
+            &params,
+            pk.get_vk(),
+            &proof_bytes,
+            &public_inputs
+        ));
+
+        // Flipping a public input must make the same proof bytes reject.
+        let mut tampered_inputs = public_inputs.clone();
+        tampered_inputs[1] = tampered_inputs[1] + Fp::one();
+        assert!(!verify_merkle_proof(
+            &params,
+            pk.get_vk(),
+            &proof_bytes,
+            &tampered_inputs
+        ));
+
+        println!("✅ Real proof verifies from bytes, and rejects a tampered instance!");
     }
 }